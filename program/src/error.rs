@@ -17,6 +17,27 @@ pub enum BlueprintError {
 
     #[error("Proposal already executed")]
     AlreadyExecuted,
+
+    #[error("Execution payload does not match the approved payload hash")]
+    PayloadMismatch,
+
+    #[error("Proposal is not yet active")]
+    NotYetActive,
+
+    #[error("Proposal has expired")]
+    Expired,
+
+    #[error("Account is not owned by this program")]
+    InvalidAccountOwner,
+
+    #[error("Account does not match its expected PDA or linkage")]
+    AccountMismatch,
+
+    #[error("Expected ed25519 signature verification is missing or targets the wrong message")]
+    MissingSignatureVerification,
+
+    #[error("Approval policy is satisfied without any real approvals, or can never be satisfied")]
+    DegeneratePolicy,
 }
 
 impl From<BlueprintError> for ProgramError {
@@ -1,5 +1,26 @@
 use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::pubkey::Pubkey;
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+
+use crate::state::ApprovalPolicy;
+
+/// Borsh-friendly stand-in for `solana_program::instruction::AccountMeta`,
+/// used to describe the accounts of the CPI an `ExecuteAction` will dispatch.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct RemoteAccountMeta {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+impl From<&RemoteAccountMeta> for AccountMeta {
+    fn from(meta: &RemoteAccountMeta) -> Self {
+        AccountMeta {
+            pubkey: meta.pubkey,
+            is_signer: meta.is_signer,
+            is_writable: meta.is_writable,
+        }
+    }
+}
 
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
 pub enum BlueprintInstruction {
@@ -11,8 +32,14 @@ pub enum BlueprintInstruction {
     InitializeBlueprint {
         /// List of approver pubkeys.
         approvers: Vec<Pubkey>,
-        /// Required approvals to execute.
+        /// Required approvals to execute, used when `policy` is `None`.
         threshold: u8,
+        /// Approval policy for the blueprint. Defaults to `ApprovalPolicy::Threshold(threshold)`
+        /// when `None`, so callers that only care about a flat threshold can keep omitting a
+        /// policy. Note this is a source-level affordance only: adding this field still changes
+        /// the instruction's Borsh wire layout, so pre-existing serialized instructions must be
+        /// rebuilt against the new variant.
+        policy: Option<ApprovalPolicy>,
     },
 
     /// Create a proposal under a blueprint.
@@ -26,6 +53,10 @@ pub enum BlueprintInstruction {
         action_type: u16,
         /// Hash of off-chain payload / intended transaction.
         payload_hash: [u8; 32],
+        /// Unix timestamp before which approvals/execution are rejected. `0` means unset.
+        not_before: i64,
+        /// Unix timestamp after which approvals/execution are rejected. `0` means unset.
+        expires_at: i64,
     },
 
     /// Approve an existing proposal.
@@ -35,10 +66,60 @@ pub enum BlueprintInstruction {
     /// 2. [writable] proposal
     ApproveAction,
 
-    /// Mark proposal executed once approvals are satisfied.
+    /// Credit approvals collected off-chain as Ed25519 signatures over
+    /// `hash(proposal || payload_hash)` -- NOT the bare `payload_hash` -- so a signature
+    /// gathered for one proposal can't be replayed onto a different proposal that happens
+    /// to share the same payload (e.g. the same action staged under a second blueprint).
+    /// Approvers must sign over that combined digest, not just `payload_hash`. The
+    /// transaction must include, at `ed25519_instruction_index`, a native `ed25519_program`
+    /// instruction verifying the signatures being credited here.
+    /// Accounts:
+    /// 0. [] blueprint
+    /// 1. [writable] proposal
+    /// 2. [] instructions sysvar
+    ApproveWithSignatures {
+        /// How many of the leading verified signatures in the ed25519 instruction to credit.
+        signature_count: u8,
+        /// Index, within the transaction, of the ed25519_program instruction to read.
+        ed25519_instruction_index: u8,
+    },
+
+    /// Execute the approved action via CPI and mark the proposal executed.
     /// Accounts:
     /// 0. [signer] executor
     /// 1. [] blueprint
     /// 2. [writable] proposal
-    ExecuteAction,
+    /// 3. [] payload (PDA, seed: ["payload", proposal]) -- ONLY when `use_payload_record` is true;
+    ///    omit this account entirely when `instruction_data` carries the bytes directly
+    /// 4. [] target_program (the program the CPI is dispatched to)
+    /// 5. accounts referenced by `account_metas`, in order (one per entry, same order)
+    ExecuteAction {
+        /// Program the reconstructed instruction is dispatched to.
+        target_program: Pubkey,
+        /// Accounts the CPI instruction is built with.
+        account_metas: Vec<RemoteAccountMeta>,
+        /// Raw instruction data for the CPI. Ignored (and should be left empty) when
+        /// `use_payload_record` is true.
+        instruction_data: Vec<u8>,
+        /// When true, source the CPI data from the on-chain payload record written via
+        /// `RecordPayload` instead of `instruction_data`, consuming one extra account.
+        use_payload_record: bool,
+    },
+
+    /// Write (a chunk of) the full action payload on-chain, spl-record style, so the
+    /// bytes an `ExecuteAction` will replay can be independently audited instead of
+    /// only trusting their hash.
+    /// Accounts:
+    /// 0. [signer] payer
+    /// 1. [] proposal
+    /// 2. [writable] payload (PDA, seed: ["payload", proposal])
+    /// 3. [] system_program
+    RecordPayload {
+        /// Total length of the full payload; fixes the account's size on first write.
+        total_len: u32,
+        /// Byte offset this chunk starts at, allowing payloads larger than one transaction.
+        offset: u32,
+        /// Chunk bytes to write at `offset`.
+        chunk: Vec<u8>,
+    },
 }
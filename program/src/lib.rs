@@ -0,0 +1,25 @@
+// `entrypoint!` (as shipped in solana-program 1.18) checks cfgs this crate never
+// declares (`feature = "custom-heap"`, `target_os = "solana"`, ...) -- harmless, but
+// newer rustc's `unexpected_cfgs` lint flags them under `-D warnings`.
+#![allow(unexpected_cfgs)]
+
+use solana_program::{
+    account_info::AccountInfo, entrypoint, entrypoint::ProgramResult, pubkey::Pubkey,
+};
+
+pub mod error;
+pub mod instruction;
+pub mod processor;
+pub mod state;
+
+use processor::Processor;
+
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    Processor::process(program_id, accounts, instruction_data)
+}
@@ -1,21 +1,246 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    ed25519_program,
     entrypoint::ProgramResult,
+    hash::hash,
+    instruction::{AccountMeta, Instruction},
     msg,
     program::{invoke_signed},
     program_error::ProgramError,
     pubkey::Pubkey,
     system_instruction,
-    sysvar::{rent::Rent, Sysvar},
+    sysvar::{self, rent::Rent, Sysvar},
 };
 
 use crate::{
     error::BlueprintError,
-    instruction::BlueprintInstruction,
-    state::{Blueprint, Proposal},
+    instruction::{BlueprintInstruction, RemoteAccountMeta},
+    state::{ApprovalPolicy, Blueprint, PayloadRecord, Proposal, PAYLOAD_RECORD_HEADER_LEN},
 };
 
+/// Recomputes `hash(program_id || accounts || data)` the same way a proposer
+/// must when choosing `payload_hash`, so `process_execute` can bind the CPI
+/// it is about to dispatch to the exact call approvers signed off on.
+fn compute_payload_hash(
+    target_program: &Pubkey,
+    account_metas: &[RemoteAccountMeta],
+    instruction_data: &[u8],
+) -> [u8; 32] {
+    let mut preimage = Vec::with_capacity(
+        32 + account_metas.len() * 34 + instruction_data.len(),
+    );
+    preimage.extend_from_slice(target_program.as_ref());
+    for meta in account_metas {
+        preimage.extend_from_slice(meta.pubkey.as_ref());
+        preimage.push(meta.is_signer as u8);
+        preimage.push(meta.is_writable as u8);
+    }
+    preimage.extend_from_slice(instruction_data);
+    hash(&preimage).to_bytes()
+}
+
+/// Evaluates an `ApprovalPolicy` against the approvers recorded on a proposal.
+fn policy_satisfied(policy: &ApprovalPolicy, approvers: &[Pubkey], approvals: &[Pubkey]) -> bool {
+    match policy {
+        ApprovalPolicy::Threshold(required) => approvals.len() >= *required as usize,
+        ApprovalPolicy::Weighted { weights, required } => {
+            let total: u32 = weights
+                .iter()
+                .filter(|(pubkey, _)| approvals.contains(pubkey))
+                .map(|(_, weight)| *weight as u32)
+                .sum();
+            total >= *required as u32
+        }
+        ApprovalPolicy::All => approvers.iter().all(|a| approvals.contains(a)),
+        ApprovalPolicy::AnyOf(policies) => policies
+            .iter()
+            .any(|p| policy_satisfied(p, approvers, approvals)),
+        ApprovalPolicy::AllOf(policies) => policies
+            .iter()
+            .all(|p| policy_satisfied(p, approvers, approvals)),
+    }
+}
+
+/// Recursively rejects degenerate policies, which `policy_satisfied` would either pass
+/// with zero real approvals or never pass at all: `Threshold(0)` or
+/// `Threshold(n > approvers.len())`, a `Weighted` node whose `required` is 0 or
+/// unreachable given its weights, `All` over an empty approver list, and an empty
+/// `AnyOf`/`AllOf`. Applies to every policy, not just caller-supplied ones,
+/// so the default `Threshold(threshold)` path is held to the same bar.
+fn validate_policy(policy: &ApprovalPolicy, approvers_len: usize) -> ProgramResult {
+    match policy {
+        ApprovalPolicy::Threshold(required) => {
+            if *required == 0 || *required as usize > approvers_len {
+                return Err(BlueprintError::DegeneratePolicy.into());
+            }
+        }
+        ApprovalPolicy::Weighted { weights, required } => {
+            let total: u32 = weights.iter().map(|(_, weight)| *weight as u32).sum();
+            if *required == 0 || *required as u32 > total {
+                return Err(BlueprintError::DegeneratePolicy.into());
+            }
+        }
+        ApprovalPolicy::All => {
+            if approvers_len == 0 {
+                return Err(BlueprintError::DegeneratePolicy.into());
+            }
+        }
+        ApprovalPolicy::AnyOf(policies) | ApprovalPolicy::AllOf(policies) => {
+            if policies.is_empty() {
+                return Err(BlueprintError::DegeneratePolicy.into());
+            }
+            for p in policies {
+                validate_policy(p, approvers_len)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Validates that `blueprint_ai` (and, if supplied, `proposal_ai`) are genuine state
+/// accounts for this program: owned by `program_id`, re-derivable from their PDA
+/// seeds, correctly cross-linked, and not holding a signer/writable combination
+/// that a legitimate caller would never present.
+fn validate_accounts(
+    program_id: &Pubkey,
+    blueprint_ai: &AccountInfo,
+    blueprint: &Blueprint,
+    proposal: Option<(&AccountInfo, &Proposal)>,
+) -> ProgramResult {
+    if blueprint_ai.owner != program_id {
+        return Err(BlueprintError::InvalidAccountOwner.into());
+    }
+    if blueprint_ai.is_signer {
+        return Err(BlueprintError::AccountMismatch.into());
+    }
+    let (blueprint_pda, _bump) = Pubkey::find_program_address(
+        &[b"blueprint", blueprint.authority.as_ref()],
+        program_id,
+    );
+    if blueprint_pda != *blueprint_ai.key {
+        return Err(BlueprintError::AccountMismatch.into());
+    }
+
+    if let Some((proposal_ai, proposal)) = proposal {
+        if proposal_ai.owner != program_id {
+            return Err(BlueprintError::InvalidAccountOwner.into());
+        }
+        if proposal_ai.is_signer || !proposal_ai.is_writable {
+            return Err(BlueprintError::AccountMismatch.into());
+        }
+        if proposal.blueprint != *blueprint_ai.key {
+            return Err(BlueprintError::AccountMismatch.into());
+        }
+        let (proposal_pda, _bump) = Pubkey::find_program_address(
+            &[b"proposal", proposal.blueprint.as_ref(), &proposal.payload_hash],
+            program_id,
+        );
+        if proposal_pda != *proposal_ai.key {
+            return Err(BlueprintError::AccountMismatch.into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Reads the full payload bytes back out of a `["payload", proposal]` PDA written by
+/// `RecordPayload`, re-deriving the PDA and cross-checking its header first.
+fn read_payload_record(
+    program_id: &Pubkey,
+    proposal_key: &Pubkey,
+    payload_ai: &AccountInfo,
+) -> Result<Vec<u8>, ProgramError> {
+    if payload_ai.owner != program_id {
+        return Err(BlueprintError::InvalidAccountOwner.into());
+    }
+    let (pda, _bump) = Pubkey::find_program_address(&[b"payload", proposal_key.as_ref()], program_id);
+    if pda != *payload_ai.key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let data = payload_ai.data.borrow();
+    let record = PayloadRecord::try_from_slice(&data[..PAYLOAD_RECORD_HEADER_LEN])?;
+    if record.proposal != *proposal_key {
+        return Err(BlueprintError::AccountMismatch.into());
+    }
+    let start = PAYLOAD_RECORD_HEADER_LEN;
+    let end = start + record.total_len as usize;
+    Ok(data[start..end].to_vec())
+}
+
+// Layout of the native ed25519 program's instruction data; see
+// `solana_program::ed25519_program` / the SDK's `Ed25519SignatureOffsets`.
+const ED25519_SIGNATURE_OFFSETS_LEN: usize = 14;
+const ED25519_SIGNATURE_LEN: usize = 64;
+const ED25519_PUBKEY_LEN: usize = 32;
+const ED25519_CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+/// Parses a native `ed25519_program` instruction's data and returns the pubkeys of
+/// every signature it verified over exactly `expected_message`, rejecting any entry
+/// that references bytes outside this same instruction (self-contained signatures only).
+fn parse_ed25519_verified_pubkeys(
+    data: &[u8],
+    expected_message: &[u8; 32],
+) -> Result<Vec<Pubkey>, ProgramError> {
+    if data.len() < 2 {
+        return Err(BlueprintError::MissingSignatureVerification.into());
+    }
+    let num_signatures = data[0] as usize;
+    let mut cursor = 2usize;
+    let mut pubkeys = Vec::with_capacity(num_signatures);
+
+    for _ in 0..num_signatures {
+        let offsets = data
+            .get(cursor..cursor + ED25519_SIGNATURE_OFFSETS_LEN)
+            .ok_or(BlueprintError::MissingSignatureVerification)?;
+        let signature_offset = u16::from_le_bytes([offsets[0], offsets[1]]) as usize;
+        let signature_instruction_index = u16::from_le_bytes([offsets[2], offsets[3]]);
+        let public_key_offset = u16::from_le_bytes([offsets[4], offsets[5]]) as usize;
+        let public_key_instruction_index = u16::from_le_bytes([offsets[6], offsets[7]]);
+        let message_data_offset = u16::from_le_bytes([offsets[8], offsets[9]]) as usize;
+        let message_data_size = u16::from_le_bytes([offsets[10], offsets[11]]) as usize;
+        let message_instruction_index = u16::from_le_bytes([offsets[12], offsets[13]]);
+
+        if signature_instruction_index != ED25519_CURRENT_INSTRUCTION
+            || public_key_instruction_index != ED25519_CURRENT_INSTRUCTION
+            || message_instruction_index != ED25519_CURRENT_INSTRUCTION
+        {
+            return Err(BlueprintError::MissingSignatureVerification.into());
+        }
+
+        let message = data
+            .get(message_data_offset..message_data_offset + message_data_size)
+            .ok_or(BlueprintError::MissingSignatureVerification)?;
+        if message != expected_message {
+            return Err(BlueprintError::MissingSignatureVerification.into());
+        }
+
+        data.get(signature_offset..signature_offset + ED25519_SIGNATURE_LEN)
+            .ok_or(BlueprintError::MissingSignatureVerification)?;
+        let pubkey_bytes = data
+            .get(public_key_offset..public_key_offset + ED25519_PUBKEY_LEN)
+            .ok_or(BlueprintError::MissingSignatureVerification)?;
+        pubkeys.push(Pubkey::new_from_array(pubkey_bytes.try_into().unwrap()));
+
+        cursor += ED25519_SIGNATURE_OFFSETS_LEN;
+    }
+
+    Ok(pubkeys)
+}
+
+/// Rejects approvals/execution outside of a proposal's `[not_before, expires_at]` window.
+fn check_proposal_timing(proposal: &Proposal, now: i64) -> ProgramResult {
+    if proposal.not_before != 0 && now < proposal.not_before {
+        return Err(BlueprintError::NotYetActive.into());
+    }
+    if proposal.expires_at != 0 && now > proposal.expires_at {
+        return Err(BlueprintError::Expired.into());
+    }
+    Ok(())
+}
+
 pub struct Processor;
 
 impl Processor {
@@ -24,14 +249,52 @@ impl Processor {
             .map_err(|_| BlueprintError::InvalidInstruction)?;
 
         match ix {
-            BlueprintInstruction::InitializeBlueprint { approvers, threshold } => {
-                Self::process_initialize_blueprint(program_id, accounts, approvers, threshold)
-            }
-            BlueprintInstruction::ProposeAction { action_type, payload_hash } => {
-                Self::process_propose(program_id, accounts, action_type, payload_hash)
-            }
+            BlueprintInstruction::InitializeBlueprint {
+                approvers,
+                threshold,
+                policy,
+            } => Self::process_initialize_blueprint(program_id, accounts, approvers, threshold, policy),
+            BlueprintInstruction::ProposeAction {
+                action_type,
+                payload_hash,
+                not_before,
+                expires_at,
+            } => Self::process_propose(
+                program_id,
+                accounts,
+                action_type,
+                payload_hash,
+                not_before,
+                expires_at,
+            ),
             BlueprintInstruction::ApproveAction => Self::process_approve(program_id, accounts),
-            BlueprintInstruction::ExecuteAction => Self::process_execute(program_id, accounts),
+            BlueprintInstruction::ApproveWithSignatures {
+                signature_count,
+                ed25519_instruction_index,
+            } => Self::process_approve_with_signatures(
+                program_id,
+                accounts,
+                signature_count,
+                ed25519_instruction_index,
+            ),
+            BlueprintInstruction::ExecuteAction {
+                target_program,
+                account_metas,
+                instruction_data,
+                use_payload_record,
+            } => Self::process_execute(
+                program_id,
+                accounts,
+                target_program,
+                account_metas,
+                instruction_data,
+                use_payload_record,
+            ),
+            BlueprintInstruction::RecordPayload {
+                total_len,
+                offset,
+                chunk,
+            } => Self::process_record_payload(program_id, accounts, total_len, offset, chunk),
         }
     }
 
@@ -40,6 +303,7 @@ impl Processor {
         accounts: &[AccountInfo],
         approvers: Vec<Pubkey>,
         threshold: u8,
+        policy: Option<ApprovalPolicy>,
     ) -> ProgramResult {
         let acc_iter = &mut accounts.iter();
         let authority = next_account_info(acc_iter)?;
@@ -49,9 +313,12 @@ impl Processor {
         if !authority.is_signer {
             return Err(ProgramError::MissingRequiredSignature);
         }
-        if threshold == 0 || threshold as usize > approvers.len() {
-            return Err(ProgramError::InvalidArgument);
-        }
+        // `threshold` only backs the default `Threshold` policy; callers supplying their
+        // own `policy` don't use it at all, but that policy is still subject to
+        // `validate_policy` below -- a custom policy must not be any easier to satisfy
+        // than a plain threshold would be.
+        let policy = policy.unwrap_or(ApprovalPolicy::Threshold(threshold));
+        validate_policy(&policy, approvers.len())?;
 
         // Create blueprint PDA account (seed: ["blueprint", authority])
         let (pda, bump) = Pubkey::find_program_address(
@@ -65,7 +332,7 @@ impl Processor {
         let blueprint = Blueprint {
             authority: *authority.key,
             approvers,
-            threshold,
+            policy,
         };
         let data = blueprint.try_to_vec()?;
         let rent = Rent::get()?;
@@ -93,6 +360,8 @@ impl Processor {
         accounts: &[AccountInfo],
         action_type: u16,
         payload_hash: [u8; 32],
+        not_before: i64,
+        expires_at: i64,
     ) -> ProgramResult {
         let acc_iter = &mut accounts.iter();
         let proposer = next_account_info(acc_iter)?;
@@ -105,6 +374,7 @@ impl Processor {
         }
 
         let blueprint = Blueprint::try_from_slice(&blueprint_ai.data.borrow())?;
+        validate_accounts(program_id, blueprint_ai, &blueprint, None)?;
 
         // Create proposal PDA (seed: ["proposal", blueprint, payload_hash])
         let (pda, bump) = Pubkey::find_program_address(
@@ -122,17 +392,23 @@ impl Processor {
             payload_hash,
             approvals: vec![],
             executed: false,
+            not_before,
+            expires_at,
         };
         let data = proposal.try_to_vec()?;
+        // `approvals` only holds `blueprint.approvers.len()` entries at most; reserve room for
+        // all of them up front so later approvals can grow the vec without reallocating the
+        // account (this instruction has no mechanism to additionally fund a rent top-up).
+        let space = data.len() + blueprint.approvers.len() * 32;
         let rent = Rent::get()?;
-        let lamports = rent.minimum_balance(data.len());
+        let lamports = rent.minimum_balance(space);
 
         invoke_signed(
             &system_instruction::create_account(
                 proposer.key,
                 proposal_ai.key,
                 lamports,
-                data.len() as u64,
+                space as u64,
                 program_id,
             ),
             &[proposer.clone(), proposal_ai.clone(), system_program.clone()],
@@ -140,11 +416,11 @@ impl Processor {
         )?;
 
         proposal_ai.data.borrow_mut()[..data.len()].copy_from_slice(&data);
-        msg!("Proposal created: action_type={} threshold={}", action_type, blueprint.threshold);
+        msg!("Proposal created: action_type={} policy={:?}", action_type, blueprint.policy);
         Ok(())
     }
 
-    fn process_approve(_program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    fn process_approve(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
         let acc_iter = &mut accounts.iter();
         let approver = next_account_info(acc_iter)?;
         let blueprint_ai = next_account_info(acc_iter)?;
@@ -155,12 +431,15 @@ impl Processor {
         }
 
         let blueprint = Blueprint::try_from_slice(&blueprint_ai.data.borrow())?;
-        let mut proposal = Proposal::try_from_slice(&proposal_ai.data.borrow())?;
+        let mut proposal = Proposal::deserialize(&mut &proposal_ai.data.borrow()[..])?;
+        validate_accounts(program_id, blueprint_ai, &blueprint, Some((proposal_ai, &proposal)))?;
 
         if proposal.executed {
             return Err(BlueprintError::AlreadyExecuted.into());
         }
 
+        check_proposal_timing(&proposal, Clock::get()?.unix_timestamp)?;
+
         // Ensure approver is in approvers list
         if !blueprint.approvers.iter().any(|k| k == approver.key) {
             return Err(BlueprintError::Unauthorized.into());
@@ -174,31 +453,89 @@ impl Processor {
         proposal.approvals.push(*approver.key);
         let data = proposal.try_to_vec()?;
         proposal_ai.data.borrow_mut()[..data.len()].copy_from_slice(&data);
-        msg!("Approved: {}/{}", proposal.approvals.len(), blueprint.threshold);
+        msg!("Approved: {} total approvals", proposal.approvals.len());
         Ok(())
     }
 
-    fn process_execute(_program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    fn process_execute(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        target_program: Pubkey,
+        account_metas: Vec<RemoteAccountMeta>,
+        instruction_data: Vec<u8>,
+        use_payload_record: bool,
+    ) -> ProgramResult {
         let acc_iter = &mut accounts.iter();
         let executor = next_account_info(acc_iter)?;
         let blueprint_ai = next_account_info(acc_iter)?;
         let proposal_ai = next_account_info(acc_iter)?;
+        // The payload record account is only present when the caller explicitly asks for
+        // the CPI data to be sourced from a prior `RecordPayload` instead of inline bytes.
+        let payload_ai = if use_payload_record {
+            Some(next_account_info(acc_iter)?)
+        } else {
+            None
+        };
+        let target_program_ai = next_account_info(acc_iter)?;
+        let remaining: Vec<&AccountInfo> = acc_iter.collect();
 
         if !executor.is_signer {
             return Err(ProgramError::MissingRequiredSignature);
         }
+        if *target_program_ai.key != target_program {
+            return Err(ProgramError::InvalidArgument);
+        }
+        if remaining.len() != account_metas.len() {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
 
         let blueprint = Blueprint::try_from_slice(&blueprint_ai.data.borrow())?;
-        let mut proposal = Proposal::try_from_slice(&proposal_ai.data.borrow())?;
+        let mut proposal = Proposal::deserialize(&mut &proposal_ai.data.borrow()[..])?;
+        validate_accounts(program_id, blueprint_ai, &blueprint, Some((proposal_ai, &proposal)))?;
 
         if proposal.executed {
             return Err(BlueprintError::AlreadyExecuted.into());
         }
 
-        if proposal.approvals.len() < blueprint.threshold as usize {
+        if !policy_satisfied(&blueprint.policy, &blueprint.approvers, &proposal.approvals) {
             return Err(BlueprintError::NotEnoughApprovals.into());
         }
 
+        check_proposal_timing(&proposal, Clock::get()?.unix_timestamp)?;
+
+        let resolved_data: Vec<u8> = match payload_ai {
+            Some(payload_ai) => read_payload_record(program_id, proposal_ai.key, payload_ai)?,
+            None => instruction_data,
+        };
+
+        let payload_hash = compute_payload_hash(&target_program, &account_metas, &resolved_data);
+        if payload_hash != proposal.payload_hash {
+            return Err(BlueprintError::PayloadMismatch.into());
+        }
+
+        let (pda, bump) = Pubkey::find_program_address(
+            &[b"blueprint", blueprint.authority.as_ref()],
+            program_id,
+        );
+        if pda != *blueprint_ai.key {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        let metas: Vec<AccountMeta> = account_metas.iter().map(AccountMeta::from).collect();
+        let ix = Instruction {
+            program_id: target_program,
+            accounts: metas,
+            data: resolved_data,
+        };
+        let mut cpi_accounts: Vec<AccountInfo> = remaining.into_iter().cloned().collect();
+        cpi_accounts.push(target_program_ai.clone());
+
+        invoke_signed(
+            &ix,
+            &cpi_accounts,
+            &[&[b"blueprint", blueprint.authority.as_ref(), &[bump]]],
+        )?;
+
         proposal.executed = true;
         let data = proposal.try_to_vec()?;
         proposal_ai.data.borrow_mut()[..data.len()].copy_from_slice(&data);
@@ -206,4 +543,129 @@ impl Processor {
         msg!("Executed proposal for action_type={} payload_hash={:?}", proposal.action_type, proposal.payload_hash);
         Ok(())
     }
+
+    fn process_record_payload(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        total_len: u32,
+        offset: u32,
+        chunk: Vec<u8>,
+    ) -> ProgramResult {
+        let acc_iter = &mut accounts.iter();
+        let payer = next_account_info(acc_iter)?;
+        let proposal_ai = next_account_info(acc_iter)?;
+        let payload_ai = next_account_info(acc_iter)?;
+        let system_program = next_account_info(acc_iter)?;
+
+        if !payer.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        let end = offset
+            .checked_add(chunk.len() as u32)
+            .ok_or(ProgramError::InvalidArgument)?;
+        if end > total_len {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let (pda, bump) =
+            Pubkey::find_program_address(&[b"payload", proposal_ai.key.as_ref()], program_id);
+        if pda != *payload_ai.key {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        if payload_ai.data_is_empty() {
+            let header = PayloadRecord {
+                proposal: *proposal_ai.key,
+                total_len,
+            };
+            let header_data = header.try_to_vec()?;
+            let space = PAYLOAD_RECORD_HEADER_LEN + total_len as usize;
+            let rent = Rent::get()?;
+            let lamports = rent.minimum_balance(space);
+
+            invoke_signed(
+                &system_instruction::create_account(
+                    payer.key,
+                    payload_ai.key,
+                    lamports,
+                    space as u64,
+                    program_id,
+                ),
+                &[payer.clone(), payload_ai.clone(), system_program.clone()],
+                &[&[b"payload", proposal_ai.key.as_ref(), &[bump]]],
+            )?;
+            payload_ai.data.borrow_mut()[..header_data.len()].copy_from_slice(&header_data);
+        }
+
+        let record =
+            PayloadRecord::try_from_slice(&payload_ai.data.borrow()[..PAYLOAD_RECORD_HEADER_LEN])?;
+        if record.proposal != *proposal_ai.key || record.total_len != total_len {
+            return Err(BlueprintError::AccountMismatch.into());
+        }
+
+        let start = PAYLOAD_RECORD_HEADER_LEN + offset as usize;
+        payload_ai.data.borrow_mut()[start..start + chunk.len()].copy_from_slice(&chunk);
+
+        msg!("Recorded payload chunk: offset={} len={}", offset, chunk.len());
+        Ok(())
+    }
+
+    fn process_approve_with_signatures(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        signature_count: u8,
+        ed25519_instruction_index: u8,
+    ) -> ProgramResult {
+        let acc_iter = &mut accounts.iter();
+        let blueprint_ai = next_account_info(acc_iter)?;
+        let proposal_ai = next_account_info(acc_iter)?;
+        let instructions_ai = next_account_info(acc_iter)?;
+
+        if *instructions_ai.key != sysvar::instructions::id() {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let blueprint = Blueprint::try_from_slice(&blueprint_ai.data.borrow())?;
+        let mut proposal = Proposal::deserialize(&mut &proposal_ai.data.borrow()[..])?;
+        validate_accounts(program_id, blueprint_ai, &blueprint, Some((proposal_ai, &proposal)))?;
+
+        if proposal.executed {
+            return Err(BlueprintError::AlreadyExecuted.into());
+        }
+
+        check_proposal_timing(&proposal, Clock::get()?.unix_timestamp)?;
+
+        let ed25519_ix = sysvar::instructions::load_instruction_at_checked(
+            ed25519_instruction_index as usize,
+            instructions_ai,
+        )?;
+        if ed25519_ix.program_id != ed25519_program::id() {
+            return Err(BlueprintError::MissingSignatureVerification.into());
+        }
+
+        // Bind the signed digest to this specific proposal, not just its payload_hash: the
+        // same payload can be staged under multiple proposals (e.g. the same action proposed
+        // under a second blueprint), and without this binding a signature collected for one
+        // would be replayable onto any other sharing that hash.
+        let expected_message = hash(&[proposal_ai.key.as_ref(), &proposal.payload_hash].concat()).to_bytes();
+        let recovered = parse_ed25519_verified_pubkeys(&ed25519_ix.data, &expected_message)?;
+        if recovered.len() < signature_count as usize {
+            return Err(BlueprintError::MissingSignatureVerification.into());
+        }
+
+        for pubkey in recovered.into_iter().take(signature_count as usize) {
+            if blueprint.approvers.contains(&pubkey) && !proposal.approvals.contains(&pubkey) {
+                proposal.approvals.push(pubkey);
+            }
+        }
+
+        let data = proposal.try_to_vec()?;
+        proposal_ai.data.borrow_mut()[..data.len()].copy_from_slice(&data);
+        msg!(
+            "Approved via signatures: {} total approvals",
+            proposal.approvals.len()
+        );
+        Ok(())
+    }
 }
@@ -1,11 +1,86 @@
+use std::io::{Read, Result as IoResult, Write};
+
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
 
+/// Expression tree describing how a blueprint's proposals are approved,
+/// beyond a flat approval count.
+///
+/// `BorshSerialize`/`BorshDeserialize` are implemented by hand below instead of
+/// derived: the derive macros add a `Vec<ApprovalPolicy>: Borsh{Se,De}rialize`
+/// bound to the very impl that's supposed to establish `ApprovalPolicy: Borsh*`,
+/// which the trait solver can't satisfy (recursion overflow). Hand-rolling avoids
+/// generating that bound; the wire format matches what the derive would produce
+/// (a leading variant-index `u8` followed by the variant's fields).
+#[derive(Debug, Clone)]
+pub enum ApprovalPolicy {
+    /// Passes once at least this many distinct approvers from `Blueprint::approvers` have signed.
+    Threshold(u8),
+    /// Passes once the summed weight of present approvers reaches `required`.
+    Weighted {
+        weights: Vec<(Pubkey, u16)>,
+        required: u16,
+    },
+    /// Passes only once every approver in `Blueprint::approvers` has signed.
+    All,
+    /// Passes once any nested policy passes.
+    AnyOf(Vec<ApprovalPolicy>),
+    /// Passes once every nested policy passes.
+    AllOf(Vec<ApprovalPolicy>),
+}
+
+impl BorshSerialize for ApprovalPolicy {
+    fn serialize<W: Write>(&self, writer: &mut W) -> IoResult<()> {
+        match self {
+            ApprovalPolicy::Threshold(required) => {
+                0u8.serialize(writer)?;
+                required.serialize(writer)
+            }
+            ApprovalPolicy::Weighted { weights, required } => {
+                1u8.serialize(writer)?;
+                weights.serialize(writer)?;
+                required.serialize(writer)
+            }
+            ApprovalPolicy::All => 2u8.serialize(writer),
+            ApprovalPolicy::AnyOf(policies) => {
+                3u8.serialize(writer)?;
+                policies.serialize(writer)
+            }
+            ApprovalPolicy::AllOf(policies) => {
+                4u8.serialize(writer)?;
+                policies.serialize(writer)
+            }
+        }
+    }
+}
+
+impl BorshDeserialize for ApprovalPolicy {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> IoResult<Self> {
+        let tag = u8::deserialize_reader(reader)?;
+        Ok(match tag {
+            0 => ApprovalPolicy::Threshold(u8::deserialize_reader(reader)?),
+            1 => ApprovalPolicy::Weighted {
+                weights: Vec::<(Pubkey, u16)>::deserialize_reader(reader)?,
+                required: u16::deserialize_reader(reader)?,
+            },
+            2 => ApprovalPolicy::All,
+            3 => ApprovalPolicy::AnyOf(Vec::<ApprovalPolicy>::deserialize_reader(reader)?),
+            4 => ApprovalPolicy::AllOf(Vec::<ApprovalPolicy>::deserialize_reader(reader)?),
+            _ => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "invalid ApprovalPolicy variant",
+                ))
+            }
+        })
+    }
+}
+
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
 pub struct Blueprint {
     pub authority: Pubkey,
     pub approvers: Vec<Pubkey>,
-    pub threshold: u8,
+    pub policy: ApprovalPolicy,
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
@@ -16,4 +91,21 @@ pub struct Proposal {
     pub payload_hash: [u8; 32],
     pub approvals: Vec<Pubkey>,
     pub executed: bool,
+    /// Unix timestamp before which approvals/execution are rejected. `0` means unset.
+    pub not_before: i64,
+    /// Unix timestamp after which approvals/execution are rejected. `0` means unset.
+    pub expires_at: i64,
 }
+
+/// Fixed-size header stored at the front of a `["payload", proposal]` PDA, followed
+/// by `total_len` raw bytes of the action payload written by `RecordPayload`.
+/// spl-record style: the account holds arbitrary caller-written bytes rather than
+/// a typed borsh value, so `total_len` must be read back to know where they end.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct PayloadRecord {
+    pub proposal: Pubkey,
+    pub total_len: u32,
+}
+
+/// Borsh-serialized size of `PayloadRecord` (a `Pubkey` and a `u32`, both fixed-width).
+pub const PAYLOAD_RECORD_HEADER_LEN: usize = 32 + 4;
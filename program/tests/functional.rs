@@ -0,0 +1,566 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use onchain_decision_blueprints::{
+    instruction::{BlueprintInstruction, RemoteAccountMeta},
+    state::{ApprovalPolicy, Proposal},
+};
+use solana_program::{hash::hash, instruction::Instruction, pubkey::Pubkey, system_program};
+use solana_program_test::{processor, ProgramTest};
+use solana_sdk::{
+    account::Account,
+    instruction::AccountMeta,
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+
+const PROGRAM_ID: Pubkey = Pubkey::new_from_array([7u8; 32]);
+
+fn blueprint_pda(authority: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"blueprint", authority.as_ref()], &PROGRAM_ID)
+}
+
+fn proposal_pda(blueprint: &Pubkey, payload_hash: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"proposal", blueprint.as_ref(), payload_hash], &PROGRAM_ID)
+}
+
+/// Mirrors `processor::compute_payload_hash` -- a client computes this the same
+/// way the on-chain program re-derives it before executing a CPI.
+fn compute_payload_hash(
+    target_program: &Pubkey,
+    account_metas: &[RemoteAccountMeta],
+    instruction_data: &[u8],
+) -> [u8; 32] {
+    let mut preimage = Vec::new();
+    preimage.extend_from_slice(target_program.as_ref());
+    for meta in account_metas {
+        preimage.extend_from_slice(meta.pubkey.as_ref());
+        preimage.push(meta.is_signer as u8);
+        preimage.push(meta.is_writable as u8);
+    }
+    preimage.extend_from_slice(instruction_data);
+    hash(&preimage).to_bytes()
+}
+
+fn initialize_blueprint_ix(authority: &Pubkey, approvers: Vec<Pubkey>, threshold: u8) -> Instruction {
+    let (blueprint, _) = blueprint_pda(authority);
+    let data = BlueprintInstruction::InitializeBlueprint {
+        approvers,
+        threshold,
+        policy: Some(ApprovalPolicy::Threshold(threshold)),
+    }
+    .try_to_vec()
+    .unwrap();
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new(blueprint, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data,
+    }
+}
+
+fn propose_ix(
+    proposer: &Pubkey,
+    blueprint: &Pubkey,
+    action_type: u16,
+    payload_hash: [u8; 32],
+) -> Instruction {
+    let (proposal, _) = proposal_pda(blueprint, &payload_hash);
+    let data = BlueprintInstruction::ProposeAction {
+        action_type,
+        payload_hash,
+        not_before: 0,
+        expires_at: 0,
+    }
+    .try_to_vec()
+    .unwrap();
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*proposer, true),
+            AccountMeta::new_readonly(*blueprint, false),
+            AccountMeta::new(proposal, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data,
+    }
+}
+
+fn approve_ix(approver: &Pubkey, blueprint: &Pubkey, proposal: &Pubkey) -> Instruction {
+    let data = BlueprintInstruction::ApproveAction.try_to_vec().unwrap();
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*approver, true),
+            AccountMeta::new_readonly(*blueprint, false),
+            AccountMeta::new(*proposal, false),
+        ],
+        data,
+    }
+}
+
+/// Builds the `account_metas` / `instruction_data` for a CPI where `trigger_blueprint`'s
+/// PDA approves a proposal on a *different* blueprint it's listed as an approver of.
+/// This is the CPI a program-owned PDA can actually authorize via `invoke_signed`:
+/// unlike a `system_program` transfer, `ApproveAction` never requires its accounts to
+/// be owned by the account that's "spending" -- it only checks that the signer is
+/// listed in `Blueprint::approvers`, which a blueprint PDA can be for another blueprint.
+/// (It can't be for itself: `trigger_blueprint`'s pubkey would then appear twice in one
+/// instruction's accounts with conflicting `is_signer` flags, which Solana collapses to
+/// "signer everywhere" and `validate_accounts` rightly rejects.)
+fn approve_other_cpi(
+    trigger_blueprint: &Pubkey,
+    target_blueprint: &Pubkey,
+    target_proposal: &Pubkey,
+) -> (Vec<RemoteAccountMeta>, Vec<u8>) {
+    let account_metas = vec![
+        RemoteAccountMeta {
+            pubkey: *trigger_blueprint,
+            is_signer: true,
+            is_writable: false,
+        },
+        RemoteAccountMeta {
+            pubkey: *target_blueprint,
+            is_signer: false,
+            is_writable: false,
+        },
+        RemoteAccountMeta {
+            pubkey: *target_proposal,
+            is_signer: false,
+            is_writable: true,
+        },
+    ];
+    let instruction_data = BlueprintInstruction::ApproveAction.try_to_vec().unwrap();
+    (account_metas, instruction_data)
+}
+
+fn execute_approve_other_ix(
+    executor: &Pubkey,
+    trigger_blueprint: &Pubkey,
+    proposal: &Pubkey,
+    target_blueprint: &Pubkey,
+    target_proposal: &Pubkey,
+) -> Instruction {
+    let (account_metas, instruction_data) =
+        approve_other_cpi(trigger_blueprint, target_blueprint, target_proposal);
+    let data = BlueprintInstruction::ExecuteAction {
+        target_program: PROGRAM_ID,
+        account_metas,
+        instruction_data,
+        use_payload_record: false,
+    }
+    .try_to_vec()
+    .unwrap();
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*executor, true),
+            AccountMeta::new_readonly(*trigger_blueprint, false),
+            AccountMeta::new(*proposal, false),
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+            AccountMeta::new_readonly(*trigger_blueprint, false),
+            AccountMeta::new_readonly(*target_blueprint, false),
+            AccountMeta::new(*target_proposal, false),
+        ],
+        data,
+    }
+}
+
+fn program_test() -> ProgramTest {
+    ProgramTest::new(
+        "onchain_decision_blueprints",
+        PROGRAM_ID,
+        processor!(onchain_decision_blueprints::process_instruction),
+    )
+}
+
+fn custom_error(err: &TransactionError, code: u32) -> bool {
+    matches!(
+        err,
+        TransactionError::InstructionError(_, solana_sdk::instruction::InstructionError::Custom(c))
+        if *c == code
+    )
+}
+
+#[tokio::test]
+async fn happy_path_threshold_met_executes_cpi() {
+    let authority = Keypair::new();
+    let approver = Keypair::new();
+    let target_authority = Keypair::new();
+
+    let mut test = program_test();
+    test.add_account(
+        authority.pubkey(),
+        Account {
+            lamports: 10_000_000_000,
+            ..Account::default()
+        },
+    );
+    test.add_account(
+        target_authority.pubkey(),
+        Account {
+            lamports: 10_000_000_000,
+            ..Account::default()
+        },
+    );
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let (blueprint, _) = blueprint_pda(&authority.pubkey());
+    let (target_blueprint, _) = blueprint_pda(&target_authority.pubkey());
+
+    let tx = Transaction::new_signed_with_payer(
+        &[initialize_blueprint_ix(&authority.pubkey(), vec![approver.pubkey()], 1)],
+        Some(&payer.pubkey()),
+        &[&payer, &authority],
+        recent_blockhash,
+    );
+    banks_client.process_transaction(tx).await.unwrap();
+
+    // `blueprint`'s PDA is listed as the sole approver here, so it -- and only
+    // it -- can authorize approving proposals on `target_blueprint` via CPI.
+    let tx = Transaction::new_signed_with_payer(
+        &[initialize_blueprint_ix(&target_authority.pubkey(), vec![blueprint], 1)],
+        Some(&payer.pubkey()),
+        &[&payer, &target_authority],
+        recent_blockhash,
+    );
+    banks_client.process_transaction(tx).await.unwrap();
+
+    // The proposal the CPI will approve on `target_blueprint`'s behalf.
+    let target_payload_hash = [9u8; 32];
+    let (target_proposal, _) = proposal_pda(&target_blueprint, &target_payload_hash);
+    let tx = Transaction::new_signed_with_payer(
+        &[propose_ix(&payer.pubkey(), &target_blueprint, 0, target_payload_hash)],
+        Some(&payer.pubkey()),
+        &[&payer],
+        recent_blockhash,
+    );
+    banks_client.process_transaction(tx).await.unwrap();
+
+    // Exercise a real CPI: once approved, the proposal below has `blueprint`'s PDA
+    // approve `target_proposal` via `invoke_signed` -- the one kind of CPI a
+    // program-owned PDA can actually authorize.
+    let (account_metas, instruction_data) =
+        approve_other_cpi(&blueprint, &target_blueprint, &target_proposal);
+    let payload_hash = compute_payload_hash(&PROGRAM_ID, &account_metas, &instruction_data);
+    let (proposal, _) = proposal_pda(&blueprint, &payload_hash);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[propose_ix(&payer.pubkey(), &blueprint, 1, payload_hash)],
+        Some(&payer.pubkey()),
+        &[&payer],
+        recent_blockhash,
+    );
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[approve_ix(&approver.pubkey(), &blueprint, &proposal)],
+        Some(&payer.pubkey()),
+        &[&payer, &approver],
+        recent_blockhash,
+    );
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[execute_approve_other_ix(
+            &payer.pubkey(),
+            &blueprint,
+            &proposal,
+            &target_blueprint,
+            &target_proposal,
+        )],
+        Some(&payer.pubkey()),
+        &[&payer],
+        recent_blockhash,
+    );
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let target_proposal_account = banks_client.get_account(target_proposal).await.unwrap().unwrap();
+    let target_proposal_state = Proposal::try_from_slice(&target_proposal_account.data).unwrap();
+    assert_eq!(target_proposal_state.approvals, vec![blueprint]);
+
+    let proposal_account = banks_client.get_account(proposal).await.unwrap().unwrap();
+    let proposal_state = Proposal::try_from_slice(&proposal_account.data).unwrap();
+    assert!(proposal_state.executed);
+}
+
+#[tokio::test]
+async fn unauthorized_approver_rejected() {
+    let authority = Keypair::new();
+    let approver = Keypair::new();
+    let intruder = Keypair::new();
+
+    let mut test = program_test();
+    test.add_account(
+        authority.pubkey(),
+        Account {
+            lamports: 10_000_000_000,
+            ..Account::default()
+        },
+    );
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+    let (blueprint, _) = blueprint_pda(&authority.pubkey());
+
+    let tx = Transaction::new_signed_with_payer(
+        &[initialize_blueprint_ix(&authority.pubkey(), vec![approver.pubkey()], 1)],
+        Some(&payer.pubkey()),
+        &[&payer, &authority],
+        recent_blockhash,
+    );
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let payload_hash = [1u8; 32];
+    let (proposal, _) = proposal_pda(&blueprint, &payload_hash);
+    let tx = Transaction::new_signed_with_payer(
+        &[propose_ix(&payer.pubkey(), &blueprint, 0, payload_hash)],
+        Some(&payer.pubkey()),
+        &[&payer],
+        recent_blockhash,
+    );
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[approve_ix(&intruder.pubkey(), &blueprint, &proposal)],
+        Some(&payer.pubkey()),
+        &[&payer, &intruder],
+        recent_blockhash,
+    );
+    let err = banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err()
+        .unwrap();
+    assert!(custom_error(&err, onchain_decision_blueprints::error::BlueprintError::Unauthorized as u32));
+}
+
+#[tokio::test]
+async fn double_approval_rejected() {
+    let authority = Keypair::new();
+    let approver = Keypair::new();
+
+    let mut test = program_test();
+    test.add_account(
+        authority.pubkey(),
+        Account {
+            lamports: 10_000_000_000,
+            ..Account::default()
+        },
+    );
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+    let (blueprint, _) = blueprint_pda(&authority.pubkey());
+
+    let tx = Transaction::new_signed_with_payer(
+        &[initialize_blueprint_ix(&authority.pubkey(), vec![approver.pubkey()], 1)],
+        Some(&payer.pubkey()),
+        &[&payer, &authority],
+        recent_blockhash,
+    );
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let payload_hash = [2u8; 32];
+    let (proposal, _) = proposal_pda(&blueprint, &payload_hash);
+    let tx = Transaction::new_signed_with_payer(
+        &[propose_ix(&payer.pubkey(), &blueprint, 0, payload_hash)],
+        Some(&payer.pubkey()),
+        &[&payer],
+        recent_blockhash,
+    );
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[approve_ix(&approver.pubkey(), &blueprint, &proposal)],
+        Some(&payer.pubkey()),
+        &[&payer, &approver],
+        recent_blockhash,
+    );
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[approve_ix(&approver.pubkey(), &blueprint, &proposal)],
+        Some(&payer.pubkey()),
+        &[&payer, &approver],
+        recent_blockhash,
+    );
+    let err = banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err()
+        .unwrap();
+    assert!(custom_error(&err, onchain_decision_blueprints::error::BlueprintError::AlreadyApproved as u32));
+}
+
+#[tokio::test]
+async fn execute_before_threshold_rejected() {
+    let authority = Keypair::new();
+    let approver = Keypair::new();
+
+    let mut test = program_test();
+    test.add_account(
+        authority.pubkey(),
+        Account {
+            lamports: 10_000_000_000,
+            ..Account::default()
+        },
+    );
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+    let (blueprint, _) = blueprint_pda(&authority.pubkey());
+
+    let tx = Transaction::new_signed_with_payer(
+        &[initialize_blueprint_ix(&authority.pubkey(), vec![approver.pubkey()], 1)],
+        Some(&payer.pubkey()),
+        &[&payer, &authority],
+        recent_blockhash,
+    );
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let account_metas: Vec<RemoteAccountMeta> = vec![];
+    let instruction_data = vec![1u8];
+    let payload_hash = compute_payload_hash(&system_program::id(), &account_metas, &instruction_data);
+    let (proposal, _) = proposal_pda(&blueprint, &payload_hash);
+    let tx = Transaction::new_signed_with_payer(
+        &[propose_ix(&payer.pubkey(), &blueprint, 0, payload_hash)],
+        Some(&payer.pubkey()),
+        &[&payer],
+        recent_blockhash,
+    );
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let data = BlueprintInstruction::ExecuteAction {
+        target_program: system_program::id(),
+        account_metas,
+        instruction_data,
+        use_payload_record: false,
+    }
+    .try_to_vec()
+    .unwrap();
+    let ix = Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(payer.pubkey(), true),
+            AccountMeta::new(blueprint, false),
+            AccountMeta::new(proposal, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data,
+    };
+    let tx = Transaction::new_signed_with_payer(&[ix], Some(&payer.pubkey()), &[&payer], recent_blockhash);
+    let err = banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err()
+        .unwrap();
+    assert!(custom_error(&err, onchain_decision_blueprints::error::BlueprintError::NotEnoughApprovals as u32));
+}
+
+#[tokio::test]
+async fn re_execute_rejected() {
+    let authority = Keypair::new();
+    let approver = Keypair::new();
+    let target_authority = Keypair::new();
+
+    let mut test = program_test();
+    test.add_account(
+        authority.pubkey(),
+        Account {
+            lamports: 10_000_000_000,
+            ..Account::default()
+        },
+    );
+    test.add_account(
+        target_authority.pubkey(),
+        Account {
+            lamports: 10_000_000_000,
+            ..Account::default()
+        },
+    );
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+    let (blueprint, _) = blueprint_pda(&authority.pubkey());
+    let (target_blueprint, _) = blueprint_pda(&target_authority.pubkey());
+
+    let tx = Transaction::new_signed_with_payer(
+        &[initialize_blueprint_ix(&authority.pubkey(), vec![approver.pubkey()], 1)],
+        Some(&payer.pubkey()),
+        &[&payer, &authority],
+        recent_blockhash,
+    );
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[initialize_blueprint_ix(&target_authority.pubkey(), vec![blueprint], 1)],
+        Some(&payer.pubkey()),
+        &[&payer, &target_authority],
+        recent_blockhash,
+    );
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let target_payload_hash = [8u8; 32];
+    let (target_proposal, _) = proposal_pda(&target_blueprint, &target_payload_hash);
+    let tx = Transaction::new_signed_with_payer(
+        &[propose_ix(&payer.pubkey(), &target_blueprint, 0, target_payload_hash)],
+        Some(&payer.pubkey()),
+        &[&payer],
+        recent_blockhash,
+    );
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let (account_metas, instruction_data) =
+        approve_other_cpi(&blueprint, &target_blueprint, &target_proposal);
+    let payload_hash = compute_payload_hash(&PROGRAM_ID, &account_metas, &instruction_data);
+    let (proposal, _) = proposal_pda(&blueprint, &payload_hash);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[propose_ix(&payer.pubkey(), &blueprint, 1, payload_hash)],
+        Some(&payer.pubkey()),
+        &[&payer],
+        recent_blockhash,
+    );
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[approve_ix(&approver.pubkey(), &blueprint, &proposal)],
+        Some(&payer.pubkey()),
+        &[&payer, &approver],
+        recent_blockhash,
+    );
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[execute_approve_other_ix(
+            &payer.pubkey(),
+            &blueprint,
+            &proposal,
+            &target_blueprint,
+            &target_proposal,
+        )],
+        Some(&payer.pubkey()),
+        &[&payer],
+        recent_blockhash,
+    );
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let recent_blockhash = banks_client.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[execute_approve_other_ix(
+            &payer.pubkey(),
+            &blueprint,
+            &proposal,
+            &target_blueprint,
+            &target_proposal,
+        )],
+        Some(&payer.pubkey()),
+        &[&payer],
+        recent_blockhash,
+    );
+    let err = banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err()
+        .unwrap();
+    assert!(custom_error(&err, onchain_decision_blueprints::error::BlueprintError::AlreadyExecuted as u32));
+}